@@ -0,0 +1,67 @@
+use proc_macro2::Ident;
+use syn::{Expr, Generics, Pat, Type, WhereClause};
+
+pub struct Struct {
+    pub name: Ident,
+    pub generics: Generics,
+    pub where_clause: Option<WhereClause>,
+    pub fields: Vec<(Ident, Type)>,
+    pub nodes: Vec<Node>,
+}
+
+pub enum Node {
+    Element(Element),
+    Text(Text),
+    If(If),
+    For(For),
+    Match(Match),
+}
+
+pub struct Element {
+    pub name: String,
+    pub id: Option<Expr>,
+    pub classes: Vec<Expr>,
+    pub attributes: Vec<Attribute>,
+    pub splats: Vec<Expr>,
+    pub children: Vec<Node>,
+    pub close: bool,
+}
+
+pub struct Attribute {
+    pub name: String,
+    pub value: Expr,
+    pub bool: bool,
+    pub optional: bool,
+}
+
+pub enum Text {
+    String(String),
+    Expr(Expr),
+}
+
+pub struct If {
+    pub clauses: Vec<IfClause>,
+    pub default: Option<Vec<Node>>,
+}
+
+pub struct IfClause {
+    pub test: Expr,
+    pub consequent: Vec<Node>,
+}
+
+pub struct For {
+    pub pat: Pat,
+    pub expr: Expr,
+    pub body: Vec<Node>,
+}
+
+pub struct Match {
+    pub expr: Expr,
+    pub arms: Vec<Arm>,
+}
+
+pub struct Arm {
+    pub pat: Pat,
+    pub guard: Option<Expr>,
+    pub body: Vec<Node>,
+}
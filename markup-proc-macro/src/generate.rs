@@ -1,4 +1,4 @@
-use crate::ast::{Attribute, Element, For, If, IfClause, Node, Struct, Text};
+use crate::ast::{Arm, Attribute, Element, For, If, IfClause, Match, Node, Struct, Text};
 use proc_macro2::TokenStream;
 use proc_macro2::TokenTree;
 use quote::{quote, ToTokens};
@@ -44,6 +44,20 @@ impl ToTokens for Struct {
                     Ok(())
                 }
             }
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Renders directly to a writer, streaming bytes as they're
+                /// produced instead of first materializing a `String`.
+                pub fn render_to<W: std::io::Write>(&self, __writer: &mut W) -> std::io::Result<()> {
+                    let mut __adapter = markup::IoWriteAdapter::new(__writer);
+                    match std::fmt::Write::write_fmt(&mut __adapter, format_args!("{}", self)) {
+                        Ok(()) => __adapter.into_result(),
+                        Err(_) => __adapter.into_result().and(Err(std::io::Error::new(
+                            std::io::ErrorKind::Other,
+                            "formatter error",
+                        ))),
+                    }
+                }
+            }
         })
     }
 }
@@ -67,6 +81,7 @@ impl Generate for Node {
             Node::Text(text) => text.generate(builder),
             Node::If(if_) => if_.generate(builder),
             Node::For(for_) => for_.generate(builder),
+            Node::Match(match_) => match_.generate(builder),
         }
     }
 }
@@ -78,6 +93,7 @@ impl Generate for Element {
             id,
             classes,
             attributes,
+            splats,
             children,
             close,
         } = self;
@@ -101,13 +117,28 @@ impl Generate for Element {
             }
             builder.raw("\"");
         }
-        for Attribute { name, value, bool } in attributes {
+        for Attribute {
+            name,
+            value,
+            bool,
+            optional,
+        } in attributes
+        {
             if *bool {
                 builder.extend(quote!(if #value));
                 builder.paren(|builder| {
                     builder.str(" ");
                     builder.str(name);
                 });
+            } else if *optional {
+                builder.extend(quote!(if let Some(__value) = #value));
+                builder.paren(|builder| {
+                    builder.str(" ");
+                    builder.str(name);
+                    builder.raw("=\"");
+                    builder.expr(&syn::parse_quote!(__value));
+                    builder.raw("\"");
+                });
             } else {
                 builder.str(" ");
                 builder.str(name);
@@ -116,6 +147,18 @@ impl Generate for Element {
                 builder.raw("\"");
             }
         }
+        for splat in splats {
+            builder.extend(quote!(for (__k, __v) in #splat));
+            builder.paren(|builder| {
+                builder.extend(quote! {
+                    __writer.write_str(" ")?;
+                    markup::escape(&std::string::ToString::to_string(&__k), __writer)?;
+                    __writer.write_str("=\"")?;
+                    markup::Render::render(&__v, __writer)?;
+                    __writer.write_str("\"")?;
+                });
+            });
+        }
         builder.raw(">");
         children.generate(builder);
         if *close {
@@ -168,6 +211,20 @@ impl Generate for For {
     }
 }
 
+impl Generate for Match {
+    fn generate(&self, builder: &mut Builder) {
+        let Match { expr, arms } = self;
+        builder.extend(quote!(match #expr));
+        builder.paren(|builder| {
+            for Arm { pat, guard, body } in arms {
+                let guard = guard.as_ref().map(|guard| quote!(if #guard));
+                builder.extend(quote!(#pat #guard =>));
+                builder.paren(|builder| body.generate(builder));
+            }
+        })
+    }
+}
+
 #[derive(Default)]
 struct Builder {
     tokens: Vec<TokenTree>,
@@ -0,0 +1,126 @@
+use std::fmt;
+use std::io;
+
+pub trait Render {
+    fn render(&self, writer: &mut fmt::Formatter) -> fmt::Result;
+}
+
+/// Bridges a [`std::fmt::Write`] consumer (what generated `Display` impls
+/// write through) to an [`std::io::Write`] sink, so templates can stream
+/// straight to a socket or file without materializing a `String` first.
+///
+/// `std::fmt::Write::write_str` can't return an `io::Error`, so write
+/// failures are stashed in `error` and surfaced via [`into_result`].
+///
+/// [`into_result`]: IoWriteAdapter::into_result
+#[doc(hidden)]
+pub struct IoWriteAdapter<'a, W: io::Write> {
+    writer: &'a mut W,
+    error: io::Result<()>,
+}
+
+impl<'a, W: io::Write> IoWriteAdapter<'a, W> {
+    pub fn new(writer: &'a mut W) -> Self {
+        IoWriteAdapter {
+            writer,
+            error: Ok(()),
+        }
+    }
+
+    pub fn into_result(self) -> io::Result<()> {
+        self.error
+    }
+}
+
+impl<'a, W: io::Write> fmt::Write for IoWriteAdapter<'a, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        match self.writer.write_all(s.as_bytes()) {
+            Ok(()) => Ok(()),
+            Err(err) => {
+                self.error = Err(err);
+                Err(fmt::Error)
+            }
+        }
+    }
+}
+
+/// Writes `value`, rewriting `&`, `<`, `>`, and `"` to their HTML entities.
+///
+/// Exposed so generated code can escape values (e.g. spread attribute
+/// names) that aren't required to implement [`Render`] themselves.
+pub fn escape(value: &str, writer: &mut fmt::Formatter) -> fmt::Result {
+    for ch in value.chars() {
+        match ch {
+            '&' => writer.write_str("&amp;")?,
+            '<' => writer.write_str("&lt;")?,
+            '>' => writer.write_str("&gt;")?,
+            '"' => writer.write_str("&quot;")?,
+            _ => fmt::Write::write_char(writer, ch)?,
+        }
+    }
+    Ok(())
+}
+
+impl Render for str {
+    fn render(&self, writer: &mut fmt::Formatter) -> fmt::Result {
+        escape(self, writer)
+    }
+}
+
+impl Render for String {
+    fn render(&self, writer: &mut fmt::Formatter) -> fmt::Result {
+        escape(self, writer)
+    }
+}
+
+macro_rules! impl_render_with_display {
+    ($($ty:ty)*) => {
+        $(
+            impl Render for $ty {
+                fn render(&self, writer: &mut fmt::Formatter) -> fmt::Result {
+                    fmt::Display::fmt(self, writer)
+                }
+            }
+        )*
+    };
+}
+
+impl_render_with_display! {
+    bool char f32 f64
+    i8 i16 i32 i64 i128 isize
+    u8 u16 u32 u64 u128 usize
+}
+
+impl<T: Render + ?Sized> Render for &T {
+    fn render(&self, writer: &mut fmt::Formatter) -> fmt::Result {
+        (**self).render(writer)
+    }
+}
+
+impl<T: Render> Render for Option<T> {
+    fn render(&self, writer: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Some(value) => value.render(writer),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A value that renders its contents verbatim, bypassing HTML escaping.
+///
+/// Construct one with [`raw`] once you know the contents are already safe
+/// to emit unescaped (sanitized markdown, an embedded SVG fragment, a
+/// cached partial).
+pub struct Raw<T>(T);
+
+/// Wrap `value` so that it is written out unescaped wherever it is
+/// interpolated in a template.
+pub fn raw<T: fmt::Display>(value: T) -> Raw<T> {
+    Raw(value)
+}
+
+impl<T: fmt::Display> Render for Raw<T> {
+    fn render(&self, writer: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, writer)
+    }
+}